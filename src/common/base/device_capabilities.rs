@@ -1,20 +1,91 @@
 use crate::common::traits::platform::PlatformDeviceCapabilitiesGetters;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct DeviceCapabilities {
     pub bin_count: u64,
     pub bin_names: Vec<String>,
+    pub paper_names: Vec<String>,
+    pub paper_ids: Vec<u16>,
+    pub paper_sizes: Vec<(i32, i32)>,
+    pub supports_duplex: bool,
+    pub supports_color: bool,
+    pub max_copies: u16,
+    /// The `DEVMODEW.dmOrientation` ids (`DMORIENT_PORTRAIT`/`DMORIENT_LANDSCAPE`)
+    /// this printer accepts, empty if orientation can't be probed at all.
+    pub orientations: Vec<u16>,
+    pub resolutions: Vec<(i32, i32)>,
 }
 
 impl DeviceCapabilities {
+    /**
+     * Resolves the full set of capabilities in a single platform probe.
+     * Previously each field had its own getter on
+     * `PlatformDeviceCapabilitiesGetters`, which meant every field read
+     * re-ran the whole (expensive) capability probe from scratch; now the
+     * platform side runs it once and hands back the finished struct.
+     */
     pub(crate) fn from_platform_device_capabilities_getters(
         platform_device_capabilities: &dyn PlatformDeviceCapabilitiesGetters,
     ) -> DeviceCapabilities {
-        let device_capabilities = DeviceCapabilities {
-            bin_count: platform_device_capabilities.get_bin_count(),
-            bin_names: platform_device_capabilities.get_bin_names(),
+        platform_device_capabilities
+            .get_device_capabilities()
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    struct CountingPlatformDeviceCapabilitiesGetters {
+        calls: Cell<u32>,
+        capabilities: DeviceCapabilities,
+    }
+
+    impl PlatformDeviceCapabilitiesGetters for CountingPlatformDeviceCapabilitiesGetters {
+        fn get_device_capabilities(&self) -> Option<DeviceCapabilities> {
+            self.calls.set(self.calls.get() + 1);
+            Some(self.capabilities.clone())
+        }
+    }
+
+    /**
+     * Regression test for the redundant-probe bug the original per-field
+     * getter design had: building one `DeviceCapabilities` must only cost a
+     * single platform-side probe, no matter how many fields it has.
+     */
+    #[test]
+    fn from_platform_device_capabilities_getters_probes_exactly_once() {
+        let platform = CountingPlatformDeviceCapabilitiesGetters {
+            calls: Cell::new(0),
+            capabilities: DeviceCapabilities {
+                bin_count: 2,
+                bin_names: vec!["Tray 1".to_string(), "Tray 2".to_string()],
+                ..Default::default()
+            },
         };
 
-        return device_capabilities;
+        let capabilities = DeviceCapabilities::from_platform_device_capabilities_getters(&platform);
+
+        assert_eq!(platform.calls.get(), 1);
+        assert_eq!(capabilities.bin_count, 2);
+        assert_eq!(capabilities.bin_names, vec!["Tray 1", "Tray 2"]);
+    }
+
+    #[test]
+    fn from_platform_device_capabilities_getters_defaults_when_platform_reports_none() {
+        struct NoCapabilities;
+        impl PlatformDeviceCapabilitiesGetters for NoCapabilities {
+            fn get_device_capabilities(&self) -> Option<DeviceCapabilities> {
+                None
+            }
+        }
+
+        let capabilities =
+            DeviceCapabilities::from_platform_device_capabilities_getters(&NoCapabilities);
+
+        assert_eq!(capabilities.bin_count, 0);
+        assert!(capabilities.bin_names.is_empty());
     }
 }
@@ -0,0 +1,71 @@
+use crate::common::traits::platform::{PlatformActions, PlatformPrinterJobGetters};
+use crate::platform::Platform;
+use std::time::SystemTime;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrinterJobState {
+    Pending,
+    Paused,
+    Printing,
+    Completed,
+    Cancelled,
+    Error,
+    Unknown,
+}
+
+#[derive(Debug, Clone)]
+pub struct PrinterJob {
+    pub id: u64,
+    pub name: String,
+    pub state: PrinterJobState,
+    pub printer: String,
+    pub media_type: String,
+    pub created_at: SystemTime,
+    pub processed_at: Option<SystemTime>,
+    pub completed_at: Option<SystemTime>,
+}
+
+impl PrinterJob {
+    pub(crate) fn from_platform_printer_job_getters(
+        platform_job: &dyn PlatformPrinterJobGetters,
+    ) -> PrinterJob {
+        PrinterJob {
+            id: platform_job.get_id(),
+            name: platform_job.get_name(),
+            state: Platform::parse_printer_job_state(platform_job.get_state()),
+            printer: platform_job.get_printer(),
+            media_type: platform_job.get_media_type(),
+            created_at: platform_job.get_created_at(),
+            processed_at: platform_job.get_processed_at(),
+            completed_at: platform_job.get_completed_at(),
+        }
+    }
+
+    /**
+     * Cancels this job.
+     */
+    pub fn cancel(&self) -> Result<(), &'static str> {
+        Platform::cancel_job(&self.printer, self.id)
+    }
+
+    /**
+     * Pauses this job.
+     */
+    pub fn pause(&self) -> Result<(), &'static str> {
+        Platform::pause_job(&self.printer, self.id)
+    }
+
+    /**
+     * Resumes this job.
+     */
+    pub fn resume(&self) -> Result<(), &'static str> {
+        Platform::resume_job(&self.printer, self.id)
+    }
+
+    /**
+     * Restarts this job from the beginning.
+     */
+    pub fn restart(&self) -> Result<(), &'static str> {
+        Platform::restart_job(&self.printer, self.id)
+    }
+}
@@ -1,6 +1,9 @@
 pub mod device_capabilities;
 pub mod job;
+pub mod print_options;
 pub mod printer;
+pub mod registry;
+pub mod watcher;
 
 #[derive(Debug)]
 pub struct PrinterWithCapabilities {
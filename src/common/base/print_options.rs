@@ -0,0 +1,34 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Duplex {
+    Simplex,
+    Vertical,
+    Horizontal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Portrait,
+    Landscape,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorMode {
+    Monochrome,
+    Color,
+}
+
+/**
+ * Options a caller can request when submitting a print job. Every field is
+ * optional: unset fields fall back to the printer's own default, set fields
+ * are merged onto the printer's default DEVMODE before the job is opened.
+ */
+#[derive(Debug, Clone, Default)]
+pub struct PrintOptions {
+    pub copies: Option<u16>,
+    pub duplex: Option<Duplex>,
+    pub orientation: Option<Orientation>,
+    pub paper_id: Option<u16>,
+    pub source_bin_id: Option<u16>,
+    pub color_mode: Option<ColorMode>,
+    pub collate: Option<bool>,
+}
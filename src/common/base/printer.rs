@@ -0,0 +1,105 @@
+use crate::common::base::job::PrinterJob;
+use crate::common::base::print_options::PrintOptions;
+use crate::common::base::watcher::PrinterWatcher;
+use crate::common::traits::platform::{PlatformActions, PlatformPrinterGetters};
+use crate::platform::Platform;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrinterState {
+    Ready,
+    Paused,
+    Error,
+    Unknown,
+}
+
+#[derive(Debug, Clone)]
+pub struct Printer {
+    pub name: String,
+    pub is_default: bool,
+    pub system_name: String,
+    pub marker_and_model: String,
+    pub is_shared: bool,
+    pub uri: String,
+    pub location: String,
+    pub state: PrinterState,
+    pub port_name: String,
+    pub processor: String,
+    pub description: String,
+    pub data_type: String,
+}
+
+impl Printer {
+    pub(crate) fn from_platform_printer_getters(
+        platform_printer: &dyn PlatformPrinterGetters,
+    ) -> Printer {
+        Printer {
+            name: platform_printer.get_name(),
+            is_default: platform_printer.get_is_default(),
+            system_name: platform_printer.get_system_name(),
+            marker_and_model: platform_printer.get_marker_and_model(),
+            is_shared: platform_printer.get_is_shared(),
+            uri: platform_printer.get_uri(),
+            location: platform_printer.get_location(),
+            state: Platform::parse_printer_state(&platform_printer.get_state()),
+            port_name: platform_printer.get_port_name(),
+            processor: platform_printer.get_processor(),
+            description: platform_printer.get_description(),
+            data_type: platform_printer.get_data_type(),
+        }
+    }
+
+    /**
+     * Prints raw bytes on this printer.
+     */
+    pub fn print(&self, buffer: &[u8], job_name: Option<&str>) -> Result<(), &'static str> {
+        Platform::print(&self.system_name, buffer, job_name)
+    }
+
+    /**
+     * Prints a file on this printer.
+     */
+    pub fn print_file(&self, file_path: &str, job_name: Option<&str>) -> Result<(), &'static str> {
+        Platform::print_file(&self.system_name, file_path, job_name)
+    }
+
+    /**
+     * Prints raw bytes on this printer with driver options (paper, duplex,
+     * copies, ...) applied via the platform's DEVMODE equivalent.
+     */
+    pub fn print_with_options(
+        &self,
+        buffer: &[u8],
+        options: &PrintOptions,
+        job_name: Option<&str>,
+    ) -> Result<(), &'static str> {
+        Platform::print_with_options(&self.system_name, buffer, options, job_name)
+    }
+
+    /**
+     * Prints a file on this printer with driver options applied.
+     */
+    pub fn print_file_with_options(
+        &self,
+        file_path: &str,
+        options: &PrintOptions,
+        job_name: Option<&str>,
+    ) -> Result<(), &'static str> {
+        Platform::print_file_with_options(&self.system_name, file_path, options, job_name)
+    }
+
+    /**
+     * Returns this printer's jobs. When `active_only` is true, jobs that
+     * already completed, were cancelled, or errored out are left out.
+     */
+    pub fn jobs(&self, active_only: bool) -> Vec<PrinterJob> {
+        Platform::get_printer_jobs(&self.system_name, active_only)
+    }
+
+    /**
+     * Watches this printer for job and state changes. See
+     * `PrinterWatcher` for how to consume the resulting events.
+     */
+    pub fn watch(&self) -> Result<PrinterWatcher, &'static str> {
+        Platform::watch_printer(&self.system_name)
+    }
+}
@@ -0,0 +1,174 @@
+use crate::common::base::PrinterWithCapabilities;
+use crate::common::traits::platform::PlatformActions;
+use crate::platform::Platform;
+
+/**
+ * A cached, refreshable view of the system's printers and their device
+ * capabilities. Enumerating printers and probing each one's capabilities
+ * is expensive (`get_printers_with_capabilities` issues several
+ * `DeviceCapabilitiesW` round trips per printer), so `Printers` only does
+ * that work when asked to via `new`/`refresh`/`refresh_printer`, and hands
+ * back a stable, diffable snapshot the rest of the time.
+ */
+#[derive(Debug, Default)]
+pub struct Printers {
+    printers: Vec<PrinterWithCapabilities>,
+}
+
+impl Printers {
+    /**
+     * Builds a registry with a fresh enumeration of the system's printers.
+     */
+    pub fn new() -> Self {
+        let mut registry = Printers {
+            printers: Vec::new(),
+        };
+        registry.refresh();
+        registry
+    }
+
+    /**
+     * Re-enumerates every printer and its device capabilities, replacing
+     * the cached snapshot.
+     */
+    pub fn refresh(&mut self) {
+        self.printers = Platform::get_printers_with_capabilities();
+    }
+
+    /**
+     * Re-probes a single printer by system name and updates (or removes,
+     * or inserts) its entry in the cached snapshot, without touching the
+     * rest of the registry.
+     */
+    pub fn refresh_printer(&mut self, system_name: &str) {
+        let refreshed = Platform::get_printer_by_name(system_name).map(|printer| {
+            let device_capabilities = Platform::get_device_capabilities_by_name(system_name)
+                .unwrap_or_default();
+            PrinterWithCapabilities {
+                printer,
+                device_capabilities,
+            }
+        });
+
+        apply_refresh(&mut self.printers, system_name, refreshed, |entry| {
+            entry.printer.system_name.as_str()
+        });
+    }
+
+    /**
+     * Returns the cached printers as of the last `new`/`refresh`, including
+     * any entries updated since by `refresh_printer`.
+     */
+    pub fn list(&self) -> &[PrinterWithCapabilities] {
+        &self.printers
+    }
+
+    /**
+     * Looks up a cached printer by its system name.
+     */
+    pub fn by_name(&self, system_name: &str) -> Option<&PrinterWithCapabilities> {
+        self.printers
+            .iter()
+            .find(|entry| entry.printer.system_name == system_name)
+    }
+
+    /**
+     * Returns the cached entry for the system's default printer, if any.
+     */
+    pub fn default(&self) -> Option<&PrinterWithCapabilities> {
+        self.printers.iter().find(|entry| entry.printer.is_default)
+    }
+}
+
+/**
+ * Replaces, removes, or inserts the entry keyed by `key` in `entries`,
+ * depending on whether it was already cached and whether the platform
+ * still reports it. Pulled out of `refresh_printer` so this matching
+ * logic can be unit tested without going through a live platform probe.
+ */
+fn apply_refresh<T>(
+    entries: &mut Vec<T>,
+    key: &str,
+    refreshed: Option<T>,
+    key_of: impl Fn(&T) -> &str,
+) {
+    let position = entries.iter().position(|entry| key_of(entry) == key);
+
+    match (position, refreshed) {
+        (Some(index), Some(entry)) => entries[index] = entry,
+        (Some(index), None) => {
+            entries.remove(index);
+        }
+        (None, Some(entry)) => entries.push(entry),
+        (None, None) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct Entry {
+        name: &'static str,
+        value: u32,
+    }
+
+    fn key_of(entry: &Entry) -> &str {
+        entry.name
+    }
+
+    #[test]
+    fn updates_an_existing_entry_in_place() {
+        let mut entries = vec![
+            Entry { name: "a", value: 1 },
+            Entry { name: "b", value: 2 },
+        ];
+
+        apply_refresh(&mut entries, "b", Some(Entry { name: "b", value: 9 }), key_of);
+
+        assert_eq!(
+            entries,
+            vec![
+                Entry { name: "a", value: 1 },
+                Entry { name: "b", value: 9 },
+            ]
+        );
+    }
+
+    #[test]
+    fn inserts_a_newly_seen_entry() {
+        let mut entries = vec![Entry { name: "a", value: 1 }];
+
+        apply_refresh(&mut entries, "b", Some(Entry { name: "b", value: 2 }), key_of);
+
+        assert_eq!(
+            entries,
+            vec![
+                Entry { name: "a", value: 1 },
+                Entry { name: "b", value: 2 },
+            ]
+        );
+    }
+
+    #[test]
+    fn removes_an_entry_the_platform_no_longer_reports() {
+        let mut entries = vec![
+            Entry { name: "a", value: 1 },
+            Entry { name: "b", value: 2 },
+        ];
+
+        apply_refresh(&mut entries, "a", None, key_of);
+
+        assert_eq!(entries, vec![Entry { name: "b", value: 2 }]);
+    }
+
+    #[test]
+    fn is_a_no_op_for_an_unknown_entry_the_platform_still_cant_see() {
+        let mut entries = vec![Entry { name: "a", value: 1 }];
+
+        apply_refresh(&mut entries, "missing", None, key_of);
+
+        assert_eq!(entries, vec![Entry { name: "a", value: 1 }]);
+    }
+}
@@ -0,0 +1,61 @@
+use std::sync::mpsc::Receiver;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrinterEvent {
+    JobAdded { printer_name: String, job_id: u64 },
+    JobStateChanged { printer_name: String, job_id: u64 },
+    PrinterStateChanged { printer_name: String },
+}
+
+/**
+ * A handle to a background watch on a single printer's jobs and state.
+ * Events are delivered over a channel fed by the platform-specific watch
+ * thread. Dropping the watcher signals the `stop` callback supplied by the
+ * platform side, which wakes the thread up (e.g. via a cancel event) so it
+ * can close its handles and exit instead of leaking until the next change
+ * notification.
+ */
+pub struct PrinterWatcher {
+    receiver: Receiver<PrinterEvent>,
+    stop: Option<Box<dyn FnOnce() + Send>>,
+}
+
+impl PrinterWatcher {
+    pub(crate) fn new(receiver: Receiver<PrinterEvent>, stop: Box<dyn FnOnce() + Send>) -> Self {
+        PrinterWatcher {
+            receiver,
+            stop: Some(stop),
+        }
+    }
+
+    /**
+     * Blocks until the next event is available, or returns None once the
+     * watch thread has stopped.
+     */
+    pub fn recv(&self) -> Option<PrinterEvent> {
+        self.receiver.recv().ok()
+    }
+
+    /**
+     * Returns the next event if one is already queued, without blocking.
+     */
+    pub fn try_recv(&self) -> Option<PrinterEvent> {
+        self.receiver.try_recv().ok()
+    }
+}
+
+impl Iterator for PrinterWatcher {
+    type Item = PrinterEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.recv()
+    }
+}
+
+impl Drop for PrinterWatcher {
+    fn drop(&mut self) {
+        if let Some(stop) = self.stop.take() {
+            stop();
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use crate::common::base::{
     device_capabilities::DeviceCapabilities,
     job::PrinterJobState,
+    print_options::PrintOptions,
     printer::{Printer, PrinterState},
+    watcher::PrinterWatcher,
     PrinterWithCapabilities,
 };
 use std::time::SystemTime;
@@ -44,6 +46,18 @@ pub trait PlatformActions {
         file_path: &str,
         job_name: Option<&str>,
     ) -> Result<(), &'static str>;
+    fn print_with_options(
+        printer_system_name: &str,
+        buffer: &[u8],
+        options: &PrintOptions,
+        job_name: Option<&str>,
+    ) -> Result<(), &'static str>;
+    fn print_file_with_options(
+        printer_system_name: &str,
+        file_path: &str,
+        options: &PrintOptions,
+        job_name: Option<&str>,
+    ) -> Result<(), &'static str>;
     fn get_printer_jobs(
         printer_name: &str,
         active_only: bool,
@@ -54,9 +68,13 @@ pub trait PlatformActions {
     fn parse_printer_job_state(platform_state: u64) -> PrinterJobState;
     fn get_device_capabilities_by_name(printer_name: &str) -> Option<DeviceCapabilities>;
     fn get_printers_with_capabilities() -> Vec<PrinterWithCapabilities>;
+    fn cancel_job(printer_system_name: &str, job_id: u64) -> Result<(), &'static str>;
+    fn pause_job(printer_system_name: &str, job_id: u64) -> Result<(), &'static str>;
+    fn resume_job(printer_system_name: &str, job_id: u64) -> Result<(), &'static str>;
+    fn restart_job(printer_system_name: &str, job_id: u64) -> Result<(), &'static str>;
+    fn watch_printer(printer_system_name: &str) -> Result<PrinterWatcher, &'static str>;
 }
 
 pub trait PlatformDeviceCapabilitiesGetters {
-    fn get_bin_count(&self) -> u64;
-    fn get_bin_names(&self) -> Vec<String>;
+    fn get_device_capabilities(&self) -> Option<DeviceCapabilities>;
 }
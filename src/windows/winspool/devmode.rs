@@ -0,0 +1,259 @@
+#![allow(non_snake_case)]
+
+use libc::{c_int, c_short, c_ulong, c_ushort, c_void, wchar_t};
+use std::ptr;
+
+use crate::common::base::print_options::{ColorMode, Duplex, Orientation, PrintOptions};
+use crate::windows::utils::{
+    memory::{alloc_s, dealloc_s},
+    strings::str_to_wide_string,
+};
+
+#[link(name = "winspool")]
+extern "system" {
+
+    fn OpenPrinterW(
+        pPrinterName: *const wchar_t,
+        phPrinter: *mut *mut c_void,
+        pDefault: *const c_void,
+    ) -> c_int;
+
+    fn ClosePrinter(hPrinter: *mut c_void) -> c_int;
+
+    fn DocumentPropertiesW(
+        hWnd: *mut c_void,
+        hPrinter: *mut c_void,
+        pDeviceName: *const wchar_t,
+        pDevModeOutput: *mut DEVMODEW,
+        pDevModeInput: *const DEVMODEW,
+        fMode: c_ulong,
+    ) -> c_int;
+
+}
+
+const DM_OUT_BUFFER: c_ulong = 2;
+
+const DM_ORIENTATION: c_ulong = 0x00000001;
+const DM_PAPERSIZE: c_ulong = 0x00000002;
+const DM_COPIES: c_ulong = 0x00000100;
+const DM_DEFAULTSOURCE: c_ulong = 0x00000200;
+const DM_COLOR: c_ulong = 0x00000800;
+const DM_DUPLEX: c_ulong = 0x00001000;
+const DM_COLLATE: c_ulong = 0x00008000;
+
+const DMORIENT_PORTRAIT: c_short = 1;
+const DMORIENT_LANDSCAPE: c_short = 2;
+
+const DMDUP_SIMPLEX: c_short = 1;
+const DMDUP_VERTICAL: c_short = 2;
+const DMDUP_HORIZONTAL: c_short = 3;
+
+const DMCOLOR_MONOCHROME: c_short = 1;
+const DMCOLOR_COLOR: c_short = 2;
+
+const DMCOLLATE_FALSE: c_short = 0;
+const DMCOLLATE_TRUE: c_short = 1;
+
+/**
+ * The winspool DEVMODE structure carries a printer's driver settings.
+ * https://learn.microsoft.com/en/windows/win32/api/wingdi/ns-wingdi-devmodew
+ */
+#[repr(C)]
+pub struct DEVMODEW {
+    dmDeviceName: [wchar_t; 32],
+    dmSpecVersion: c_ushort,
+    dmDriverVersion: c_ushort,
+    dmSize: c_ushort,
+    dmDriverExtra: c_ushort,
+    dmFields: c_ulong,
+    dmOrientation: c_short,
+    dmPaperSize: c_short,
+    dmPaperLength: c_short,
+    dmPaperWidth: c_short,
+    dmScale: c_short,
+    dmCopies: c_short,
+    dmDefaultSource: c_short,
+    dmPrintQuality: c_short,
+    dmColor: c_short,
+    dmDuplex: c_short,
+    dmYResolution: c_short,
+    dmTTOption: c_short,
+    dmCollate: c_short,
+    dmFormName: [wchar_t; 32],
+    dmLogPixels: c_ushort,
+    dmBitsPerPel: c_ulong,
+    dmPelsWidth: c_ulong,
+    dmPelsHeight: c_ulong,
+    dmDisplayFlags: c_ulong,
+    dmDisplayFrequency: c_ulong,
+    dmICMMethod: c_ulong,
+    dmICMIntent: c_ulong,
+    dmMediaType: c_ulong,
+    dmDitherType: c_ulong,
+    dmReserved1: c_ulong,
+    dmReserved2: c_ulong,
+    dmPanningWidth: c_ulong,
+    dmPanningHeight: c_ulong,
+}
+
+/**
+ * Opens `printer_name`, reads its default DEVMODE via DocumentPropertiesW,
+ * merges `options` on top of it (setting the matching dmFields bits) and
+ * returns the resulting DEVMODEW so the caller can pass it along when
+ * opening the printer for the actual job. Returns None if the printer or
+ * its default DEVMODE can't be retrieved.
+ */
+pub fn build_devmode(printer_name: &str, options: &PrintOptions) -> Option<&'static mut DEVMODEW> {
+    let printer_name_wide = str_to_wide_string(printer_name);
+    let printer_name_ptr = printer_name_wide.as_ptr();
+    let mut printer_handle: *mut c_void = ptr::null_mut();
+
+    if unsafe { OpenPrinterW(printer_name_ptr, &mut printer_handle, ptr::null()) } == 0 {
+        return None;
+    }
+
+    let devmode_size = unsafe {
+        DocumentPropertiesW(
+            ptr::null_mut(),
+            printer_handle,
+            printer_name_ptr,
+            ptr::null_mut(),
+            ptr::null(),
+            0,
+        )
+    };
+
+    if devmode_size <= 0 {
+        unsafe { ClosePrinter(printer_handle) };
+        return None;
+    }
+
+    let devmode_ptr = alloc_s::<DEVMODEW>(devmode_size as c_ulong);
+    let filled = unsafe {
+        DocumentPropertiesW(
+            ptr::null_mut(),
+            printer_handle,
+            printer_name_ptr,
+            devmode_ptr,
+            ptr::null(),
+            DM_OUT_BUFFER,
+        )
+    };
+
+    unsafe { ClosePrinter(printer_handle) };
+
+    if filled < 0 {
+        dealloc_s::<DEVMODEW>(devmode_ptr);
+        return None;
+    }
+
+    let devmode = unsafe { &mut *devmode_ptr };
+    apply_options(devmode, options);
+
+    Some(devmode)
+}
+
+/**
+ * Frees a DEVMODEW previously returned by `build_devmode`. Callers must
+ * call this once they're done opening the printer with it, mirroring the
+ * `enum_printers`/`free` pairing in `winspool::info`.
+ */
+pub fn free_devmode(devmode: &'static mut DEVMODEW) {
+    dealloc_s::<DEVMODEW>(devmode as *mut DEVMODEW);
+}
+
+fn apply_options(devmode: &mut DEVMODEW, options: &PrintOptions) {
+    if let Some(copies) = options.copies {
+        devmode.dmFields |= DM_COPIES;
+        devmode.dmCopies = copies as c_short;
+    }
+    if let Some(duplex) = options.duplex {
+        devmode.dmFields |= DM_DUPLEX;
+        devmode.dmDuplex = match duplex {
+            Duplex::Simplex => DMDUP_SIMPLEX,
+            Duplex::Vertical => DMDUP_VERTICAL,
+            Duplex::Horizontal => DMDUP_HORIZONTAL,
+        };
+    }
+    if let Some(orientation) = options.orientation {
+        devmode.dmFields |= DM_ORIENTATION;
+        devmode.dmOrientation = match orientation {
+            Orientation::Portrait => DMORIENT_PORTRAIT,
+            Orientation::Landscape => DMORIENT_LANDSCAPE,
+        };
+    }
+    if let Some(paper_id) = options.paper_id {
+        devmode.dmFields |= DM_PAPERSIZE;
+        devmode.dmPaperSize = paper_id as c_short;
+    }
+    if let Some(source_bin_id) = options.source_bin_id {
+        devmode.dmFields |= DM_DEFAULTSOURCE;
+        devmode.dmDefaultSource = source_bin_id as c_short;
+    }
+    if let Some(color_mode) = options.color_mode {
+        devmode.dmFields |= DM_COLOR;
+        devmode.dmColor = match color_mode {
+            ColorMode::Monochrome => DMCOLOR_MONOCHROME,
+            ColorMode::Color => DMCOLOR_COLOR,
+        };
+    }
+    if let Some(collate) = options.collate {
+        devmode.dmFields |= DM_COLLATE;
+        devmode.dmCollate = if collate {
+            DMCOLLATE_TRUE
+        } else {
+            DMCOLLATE_FALSE
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zeroed_devmode() -> DEVMODEW {
+        unsafe { std::mem::zeroed() }
+    }
+
+    #[test]
+    fn unset_options_leave_devmode_untouched() {
+        let mut devmode = zeroed_devmode();
+        apply_options(&mut devmode, &PrintOptions::default());
+
+        assert_eq!(devmode.dmFields, 0);
+        assert_eq!(devmode.dmCopies, 0);
+        assert_eq!(devmode.dmDuplex, 0);
+    }
+
+    #[test]
+    fn set_options_merge_onto_devmode_and_set_dm_fields_bits() {
+        let mut devmode = zeroed_devmode();
+        let options = PrintOptions {
+            copies: Some(3),
+            duplex: Some(Duplex::Horizontal),
+            orientation: Some(Orientation::Landscape),
+            paper_id: Some(9),
+            source_bin_id: Some(4),
+            color_mode: Some(ColorMode::Color),
+            collate: Some(true),
+        };
+
+        apply_options(&mut devmode, &options);
+
+        assert_eq!(devmode.dmFields & DM_COPIES, DM_COPIES);
+        assert_eq!(devmode.dmFields & DM_DUPLEX, DM_DUPLEX);
+        assert_eq!(devmode.dmFields & DM_ORIENTATION, DM_ORIENTATION);
+        assert_eq!(devmode.dmFields & DM_PAPERSIZE, DM_PAPERSIZE);
+        assert_eq!(devmode.dmFields & DM_DEFAULTSOURCE, DM_DEFAULTSOURCE);
+        assert_eq!(devmode.dmFields & DM_COLOR, DM_COLOR);
+        assert_eq!(devmode.dmFields & DM_COLLATE, DM_COLLATE);
+
+        assert_eq!(devmode.dmCopies, 3);
+        assert_eq!(devmode.dmDuplex, DMDUP_HORIZONTAL);
+        assert_eq!(devmode.dmOrientation, DMORIENT_LANDSCAPE);
+        assert_eq!(devmode.dmPaperSize, 9);
+        assert_eq!(devmode.dmDefaultSource, 4);
+        assert_eq!(devmode.dmColor, DMCOLOR_COLOR);
+        assert_eq!(devmode.dmCollate, DMCOLLATE_TRUE);
+    }
+}
@@ -64,7 +64,7 @@ pub struct PRINTER_INFO_2W {
     DefaultPriority: c_ulong,
     StartTime: c_ulong,
     UntilTime: c_ulong,
-    Status: c_ulong,
+    pub(crate) Status: c_ulong,
     cJobs: c_ulong,
     AveragePPM: c_ulong,
 }
@@ -169,6 +169,17 @@ pub fn free(printers: &'static [PRINTER_INFO_2W]) {
 pub fn get_device_capabilities(printer: &PRINTER_INFO_2W) -> Option<DeviceCapabilities> {
     const DC_BINS: c_ushort = 6;
     const DC_BINNAMES: c_ushort = 12;
+    const DC_PAPERS: c_ushort = 2;
+    const DC_PAPERSIZE: c_ushort = 3;
+    const DC_PAPERNAMES: c_ushort = 16;
+    const DC_ORIENTATION: c_ushort = 17;
+    const DC_COPIES: c_ushort = 18;
+    const DC_DUPLEX: c_ushort = 7;
+    const DC_COLORDEVICE: c_ushort = 32;
+    const DC_ENUMRESOLUTIONS: c_ushort = 13;
+
+    const DMORIENT_PORTRAIT: u16 = 1;
+    const DMORIENT_LANDSCAPE: u16 = 2;
 
     let printer_name = printer.pPrinterName;
     let port_name = printer.pPortName;
@@ -230,21 +241,169 @@ pub fn get_device_capabilities(printer: &PRINTER_INFO_2W) -> Option<DeviceCapabi
     dealloc_s::<c_ushort>(bin_ids_ptr);
     dealloc_s::<wchar_t>(bin_names_ptr);
 
+    // Step 4: Get number of papers (DC_PAPERS), then the paper IDs
+    let paper_count =
+        unsafe { DeviceCapabilitiesW(printer_name, port_name, DC_PAPERS, ptr::null_mut(), dev_mode) };
+
+    let mut paper_ids = Vec::new();
+    let mut paper_sizes = Vec::new();
+    let mut paper_names = Vec::new();
+
+    if paper_count > 0 {
+        let paper_count = paper_count as usize;
+
+        let paper_ids_size = paper_count * std::mem::size_of::<c_ushort>();
+        let paper_ids_ptr = alloc_s::<c_ushort>(paper_ids_size as c_ulong);
+        let paper_ids_result = unsafe {
+            DeviceCapabilitiesW(
+                printer_name,
+                port_name,
+                DC_PAPERS,
+                paper_ids_ptr as *mut wchar_t,
+                dev_mode,
+            )
+        };
+
+        if paper_ids_result as usize == paper_count {
+            let ids_slice = unsafe { slice::from_raw_parts(paper_ids_ptr, paper_count) };
+            paper_ids.extend_from_slice(ids_slice);
+        }
+        dealloc_s::<c_ushort>(paper_ids_ptr);
+
+        // Step 5: Paper sizes (DC_PAPERSIZE), each entry is two LONGs (cx, cy)
+        let paper_sizes_size = paper_count * 2 * std::mem::size_of::<c_int>();
+        let paper_sizes_ptr = alloc_s::<c_int>(paper_sizes_size as c_ulong);
+        let paper_sizes_result = unsafe {
+            DeviceCapabilitiesW(
+                printer_name,
+                port_name,
+                DC_PAPERSIZE,
+                paper_sizes_ptr as *mut wchar_t,
+                dev_mode,
+            )
+        };
+
+        if paper_sizes_result as usize == paper_count {
+            let sizes_slice = unsafe { slice::from_raw_parts(paper_sizes_ptr, paper_count * 2) };
+            for i in 0..paper_count {
+                paper_sizes.push((sizes_slice[i * 2], sizes_slice[i * 2 + 1]));
+            }
+        }
+        dealloc_s::<c_int>(paper_sizes_ptr);
+
+        // Step 6: Paper names (DC_PAPERNAMES), each name is 64 WCHARs
+        let paper_name_size = 64 * std::mem::size_of::<wchar_t>();
+        let paper_names_buffer_size = paper_count * paper_name_size;
+        let paper_names_ptr = alloc_s::<wchar_t>(paper_names_buffer_size as c_ulong);
+        let paper_names_result = unsafe {
+            DeviceCapabilitiesW(
+                printer_name,
+                port_name,
+                DC_PAPERNAMES,
+                paper_names_ptr,
+                dev_mode,
+            )
+        };
+
+        if paper_names_result as usize == paper_count {
+            for i in 0..paper_count {
+                let offset = i * 64;
+                let name_slice = unsafe { slice::from_raw_parts(paper_names_ptr.add(offset), 64) };
+                let name = wchar_t_to_string(name_slice.as_ptr());
+                paper_names.push(name);
+            }
+        }
+        dealloc_s::<wchar_t>(paper_names_ptr);
+    }
+
+    // Step 7: Scalar capabilities that are returned directly by the first call
+    let supports_duplex = unsafe {
+        DeviceCapabilitiesW(printer_name, port_name, DC_DUPLEX, ptr::null_mut(), dev_mode) == 1
+    };
+    let supports_color = unsafe {
+        DeviceCapabilitiesW(
+            printer_name,
+            port_name,
+            DC_COLORDEVICE,
+            ptr::null_mut(),
+            dev_mode,
+        ) == 1
+    };
+    let max_copies = unsafe {
+        DeviceCapabilitiesW(printer_name, port_name, DC_COPIES, ptr::null_mut(), dev_mode)
+    }
+    .max(0) as u16;
+    // DC_ORIENTATION itself returns the number of degrees portrait must be
+    // rotated to produce landscape, not a list of orientation ids - it can't
+    // be turned into usable DEVMODEW.dmOrientation values directly. Every
+    // printer that answers it at all accepts both standard orientations via
+    // DEVMODEW, so a successful probe means both ids are usable.
+    let orientation_supported = unsafe {
+        DeviceCapabilitiesW(
+            printer_name,
+            port_name,
+            DC_ORIENTATION,
+            ptr::null_mut(),
+            dev_mode,
+        )
+    } >= 0;
+    let orientations = if orientation_supported {
+        vec![DMORIENT_PORTRAIT, DMORIENT_LANDSCAPE]
+    } else {
+        Vec::new()
+    };
+
+    // Step 8: Supported resolutions (DC_ENUMRESOLUTIONS), each entry is two LONGs (x dpi, y dpi)
+    let resolution_count = unsafe {
+        DeviceCapabilitiesW(
+            printer_name,
+            port_name,
+            DC_ENUMRESOLUTIONS,
+            ptr::null_mut(),
+            dev_mode,
+        )
+    };
+
+    let mut resolutions = Vec::new();
+    if resolution_count > 0 {
+        let resolution_count = resolution_count as usize;
+        let resolutions_size = resolution_count * 2 * std::mem::size_of::<c_int>();
+        let resolutions_ptr = alloc_s::<c_int>(resolutions_size as c_ulong);
+        let resolutions_result = unsafe {
+            DeviceCapabilitiesW(
+                printer_name,
+                port_name,
+                DC_ENUMRESOLUTIONS,
+                resolutions_ptr as *mut wchar_t,
+                dev_mode,
+            )
+        };
+
+        if resolutions_result as usize == resolution_count {
+            let res_slice = unsafe { slice::from_raw_parts(resolutions_ptr, resolution_count * 2) };
+            for i in 0..resolution_count {
+                resolutions.push((res_slice[i * 2], res_slice[i * 2 + 1]));
+            }
+        }
+        dealloc_s::<c_int>(resolutions_ptr);
+    }
+
     Some(DeviceCapabilities {
         bin_count: bin_count as u64,
         bin_names,
+        paper_names,
+        paper_ids,
+        paper_sizes,
+        supports_duplex,
+        supports_color,
+        max_copies,
+        orientations,
+        resolutions,
     })
 }
 
 impl PlatformDeviceCapabilitiesGetters for PRINTER_INFO_2W {
-    fn get_bin_count(&self) -> u64 {
-        get_device_capabilities(self)
-            .map(|capabilities| capabilities.bin_count)
-            .unwrap_or(0)
-    }
-    fn get_bin_names(&self) -> Vec<String> {
+    fn get_device_capabilities(&self) -> Option<DeviceCapabilities> {
         get_device_capabilities(self)
-            .map(|capabilities| capabilities.bin_names)
-            .unwrap_or_default()
     }
 }
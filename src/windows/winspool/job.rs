@@ -0,0 +1,82 @@
+#![allow(non_snake_case)]
+
+use libc::{c_int, c_ulong, c_void, wchar_t};
+use std::ptr;
+
+use crate::windows::utils::strings::str_to_wide_string;
+
+#[link(name = "winspool")]
+extern "system" {
+
+    fn OpenPrinterW(
+        pPrinterName: *const wchar_t,
+        phPrinter: *mut *mut c_void,
+        pDefault: *const c_void,
+    ) -> c_int;
+
+    fn ClosePrinter(hPrinter: *mut c_void) -> c_int;
+
+    fn SetJobW(
+        hPrinter: *mut c_void,
+        JobId: c_ulong,
+        Level: c_ulong,
+        pJob: *const c_void,
+        Command: c_ulong,
+    ) -> c_int;
+
+}
+
+const JOB_CONTROL_PAUSE: c_ulong = 1;
+const JOB_CONTROL_RESUME: c_ulong = 2;
+const JOB_CONTROL_CANCEL: c_ulong = 3;
+const JOB_CONTROL_RESTART: c_ulong = 4;
+
+/**
+ * Opens a handle to `printer_name`, issues a SetJobW control command against
+ * `job_id` and closes the handle again, regardless of the outcome.
+ */
+fn control_job(printer_name: &str, job_id: u64, command: c_ulong) -> Result<(), &'static str> {
+    let printer_name_wide = str_to_wide_string(printer_name);
+    let printer_name_ptr = printer_name_wide.as_ptr();
+    let mut printer_handle: *mut c_void = ptr::null_mut();
+
+    let opened = unsafe { OpenPrinterW(printer_name_ptr, &mut printer_handle, ptr::null()) };
+
+    if opened == 0 {
+        return Err("Failed to open the printer");
+    }
+
+    let result = unsafe {
+        SetJobW(
+            printer_handle,
+            job_id as c_ulong,
+            0,
+            ptr::null(),
+            command,
+        )
+    };
+
+    unsafe { ClosePrinter(printer_handle) };
+
+    if result == 0 {
+        return Err("Failed to control the print job");
+    }
+
+    Ok(())
+}
+
+pub fn cancel_job(printer_name: &str, job_id: u64) -> Result<(), &'static str> {
+    control_job(printer_name, job_id, JOB_CONTROL_CANCEL)
+}
+
+pub fn pause_job(printer_name: &str, job_id: u64) -> Result<(), &'static str> {
+    control_job(printer_name, job_id, JOB_CONTROL_PAUSE)
+}
+
+pub fn resume_job(printer_name: &str, job_id: u64) -> Result<(), &'static str> {
+    control_job(printer_name, job_id, JOB_CONTROL_RESUME)
+}
+
+pub fn restart_job(printer_name: &str, job_id: u64) -> Result<(), &'static str> {
+    control_job(printer_name, job_id, JOB_CONTROL_RESTART)
+}
@@ -0,0 +1,308 @@
+#![allow(non_snake_case)]
+
+use libc::{c_int, c_ulong, c_ushort, c_void, wchar_t};
+use std::collections::HashMap;
+use std::ptr;
+use std::sync::mpsc;
+use std::thread;
+
+use crate::common::base::watcher::{PrinterEvent, PrinterWatcher};
+use crate::windows::utils::{
+    memory::{alloc_s, dealloc_s},
+    strings::str_to_wide_string,
+};
+use crate::windows::winspool::info::enum_printers;
+
+#[link(name = "winspool")]
+extern "system" {
+
+    fn OpenPrinterW(
+        pPrinterName: *const wchar_t,
+        phPrinter: *mut *mut c_void,
+        pDefault: *const c_void,
+    ) -> c_int;
+
+    fn ClosePrinter(hPrinter: *mut c_void) -> c_int;
+
+    fn FindFirstPrinterChangeNotification(
+        hPrinter: *mut c_void,
+        fdwFlags: c_ulong,
+        fdwOptions: c_ulong,
+        pPrinterNotifyOptions: *const c_void,
+    ) -> *mut c_void;
+
+    fn FindNextPrinterChangeNotification(
+        hChange: *mut c_void,
+        pdwChange: *mut c_ulong,
+        pPrinterNotifyOptions: *const c_void,
+        ppPrinterNotifyInfo: *mut *mut c_void,
+    ) -> c_int;
+
+    fn FindClosePrinterChangeNotification(hChange: *mut c_void) -> c_int;
+
+    fn EnumJobsW(
+        hPrinter: *mut c_void,
+        FirstJob: c_ulong,
+        NoJobs: c_ulong,
+        Level: c_ulong,
+        pJob: *mut JOB_INFO_1W,
+        cbBuf: c_ulong,
+        pcbNeeded: *mut c_ulong,
+        pcReturned: *mut c_ulong,
+    ) -> c_int;
+
+}
+
+#[link(name = "kernel32")]
+extern "system" {
+    fn WaitForMultipleObjects(
+        nCount: c_ulong,
+        lpHandles: *const *mut c_void,
+        bWaitAll: c_int,
+        dwMilliseconds: c_ulong,
+    ) -> c_ulong;
+
+    fn CreateEventW(
+        lpEventAttributes: *const c_void,
+        bManualReset: c_int,
+        bInitialState: c_int,
+        lpName: *const wchar_t,
+    ) -> *mut c_void;
+
+    fn SetEvent(hEvent: *mut c_void) -> c_int;
+
+    fn CloseHandle(hObject: *mut c_void) -> c_int;
+}
+
+const PRINTER_CHANGE_JOB: c_ulong = 0xFF00;
+const PRINTER_CHANGE_PRINTER: c_ulong = 0x000000FF;
+const PRINTER_CHANGE_ALL: c_ulong = PRINTER_CHANGE_JOB | PRINTER_CHANGE_PRINTER;
+
+const INVALID_HANDLE_VALUE: *mut c_void = -1isize as *mut c_void;
+const WAIT_OBJECT_0: c_ulong = 0;
+const INFINITE: c_ulong = 0xFFFFFFFF;
+
+/**
+ * A raw Windows HANDLE. Handles aren't `Send` by default since the
+ * compiler can't know they're safe to move across threads, but winspool
+ * and kernel32 handles are - they're just opaque kernel object IDs. This
+ * wrapper is the one place that assumption is made explicit.
+ */
+#[derive(Clone, Copy)]
+struct SendHandle(*mut c_void);
+unsafe impl Send for SendHandle {}
+
+/**
+ * The winspool JOB_INFO_1 structure used by EnumJobsW at level 1.
+ * https://learn.microsoft.com/en/windows/win32/printdocs/job-info-1
+ */
+#[repr(C)]
+struct JOB_INFO_1W {
+    JobId: c_ulong,
+    pPrinterName: *mut wchar_t,
+    pMachineName: *mut wchar_t,
+    pUserName: *mut wchar_t,
+    pDocument: *mut wchar_t,
+    pDatatype: *mut wchar_t,
+    pStatus: *mut wchar_t,
+    Status: c_ulong,
+    Priority: c_ulong,
+    Position: c_ulong,
+    TotalPages: c_ulong,
+    PagesPrinted: c_ulong,
+    Submitted: [c_ushort; 8],
+}
+
+/**
+ * Returns the job id -> status of every job currently queued on an already
+ * open printer handle, using the standard EnumJobsW size-probe-then-fill
+ * pattern. Returns an empty map on failure.
+ */
+fn enum_jobs(printer_handle: *mut c_void) -> HashMap<u64, u64> {
+    let mut bytes_needed: c_ulong = 0;
+    let mut jobs_returned: c_ulong = 0;
+
+    unsafe {
+        EnumJobsW(
+            printer_handle,
+            0,
+            c_ulong::MAX,
+            1,
+            ptr::null_mut(),
+            0,
+            &mut bytes_needed,
+            &mut jobs_returned,
+        )
+    };
+
+    if bytes_needed == 0 {
+        return HashMap::new();
+    }
+
+    let jobs_ptr = alloc_s::<JOB_INFO_1W>(bytes_needed);
+    let result = unsafe {
+        EnumJobsW(
+            printer_handle,
+            0,
+            c_ulong::MAX,
+            1,
+            jobs_ptr,
+            bytes_needed,
+            &mut bytes_needed,
+            &mut jobs_returned,
+        )
+    };
+
+    let mut jobs = HashMap::new();
+    if result != 0 {
+        let jobs_slice =
+            unsafe { std::slice::from_raw_parts(jobs_ptr, jobs_returned as usize) };
+        for job in jobs_slice {
+            jobs.insert(job.JobId as u64, job.Status as u64);
+        }
+    }
+
+    dealloc_s::<JOB_INFO_1W>(jobs_ptr);
+    jobs
+}
+
+/**
+ * Spawns a background thread that waits on a change notification handle for
+ * `printer_name` and, on every signal, re-enumerates the printer's jobs and
+ * state, diffing against the last snapshot to decide which `PrinterEvent`s
+ * to emit. The thread also waits on a cancel event that the returned
+ * `PrinterWatcher` signals when dropped, so it exits promptly instead of
+ * only noticing on the next change notification.
+ */
+pub fn watch_printer(printer_name: &str) -> Result<PrinterWatcher, &'static str> {
+    let printer_name_owned = printer_name.to_string();
+    let printer_name_wide = str_to_wide_string(printer_name);
+    let printer_name_ptr = printer_name_wide.as_ptr();
+    let mut printer_handle: *mut c_void = ptr::null_mut();
+
+    if unsafe { OpenPrinterW(printer_name_ptr, &mut printer_handle, ptr::null()) } == 0 {
+        return Err("Failed to open the printer");
+    }
+
+    // Snapshot the printer's current status and queued jobs before we start
+    // watching for changes, so the loop only ever reports real deltas
+    // instead of treating every already-queued job as newly added on the
+    // first notification.
+    let initial_status = enum_printers(Some(printer_name))
+        .first()
+        .map(|printer| printer.Status);
+    let initial_jobs = enum_jobs(printer_handle);
+
+    let change_handle = unsafe {
+        FindFirstPrinterChangeNotification(printer_handle, PRINTER_CHANGE_ALL, 0, ptr::null())
+    };
+
+    if change_handle == INVALID_HANDLE_VALUE || change_handle.is_null() {
+        unsafe { ClosePrinter(printer_handle) };
+        return Err("Failed to register for printer change notifications");
+    }
+
+    let stop_handle = unsafe { CreateEventW(ptr::null(), 1, 0, ptr::null()) };
+    if stop_handle.is_null() {
+        unsafe {
+            FindClosePrinterChangeNotification(change_handle);
+            ClosePrinter(printer_handle);
+        }
+        return Err("Failed to create the watcher's cancel event");
+    }
+
+    let printer_handle = SendHandle(printer_handle);
+    let change_handle = SendHandle(change_handle);
+    let stop_handle = SendHandle(stop_handle);
+
+    let (sender, receiver) = mpsc::channel::<PrinterEvent>();
+
+    thread::spawn(move || {
+        let printer_handle = printer_handle;
+        let change_handle = change_handle;
+        let stop_handle = stop_handle;
+
+        let mut last_status: Option<c_ulong> = initial_status;
+        let mut last_jobs: HashMap<u64, u64> = initial_jobs;
+
+        let wait_handles = [change_handle.0, stop_handle.0];
+
+        loop {
+            let wait_result =
+                unsafe { WaitForMultipleObjects(2, wait_handles.as_ptr(), 0, INFINITE) };
+
+            if wait_result != WAIT_OBJECT_0 {
+                // Either the cancel event fired or the wait failed; stop.
+                break;
+            }
+
+            let mut change: c_ulong = 0;
+            let drained = unsafe {
+                FindNextPrinterChangeNotification(
+                    change_handle.0,
+                    &mut change,
+                    ptr::null(),
+                    ptr::null_mut(),
+                )
+            };
+
+            if drained == 0 {
+                break;
+            }
+
+            if let Some(printer) = enum_printers(Some(&printer_name_owned)).first() {
+                if change & PRINTER_CHANGE_PRINTER != 0 && last_status != Some(printer.Status) {
+                    last_status = Some(printer.Status);
+                    if sender
+                        .send(PrinterEvent::PrinterStateChanged {
+                            printer_name: printer_name_owned.clone(),
+                        })
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+
+                if change & PRINTER_CHANGE_JOB != 0 {
+                    let current_jobs = enum_jobs(printer_handle.0);
+
+                    for (job_id, status) in &current_jobs {
+                        let event = match last_jobs.get(job_id) {
+                            None => Some(PrinterEvent::JobAdded {
+                                printer_name: printer_name_owned.clone(),
+                                job_id: *job_id,
+                            }),
+                            Some(previous_status) if previous_status != status => {
+                                Some(PrinterEvent::JobStateChanged {
+                                    printer_name: printer_name_owned.clone(),
+                                    job_id: *job_id,
+                                })
+                            }
+                            Some(_) => None,
+                        };
+
+                        if let Some(event) = event {
+                            if sender.send(event).is_err() {
+                                break;
+                            }
+                        }
+                    }
+
+                    last_jobs = current_jobs;
+                }
+            }
+        }
+
+        unsafe {
+            FindClosePrinterChangeNotification(change_handle.0);
+            ClosePrinter(printer_handle.0);
+            CloseHandle(stop_handle.0);
+        }
+    });
+
+    let stop = Box::new(move || {
+        unsafe { SetEvent(stop_handle.0) };
+    });
+
+    Ok(PrinterWatcher::new(receiver, stop))
+}